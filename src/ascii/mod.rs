@@ -2,6 +2,67 @@ use core::panic;
 
 use image::{Pixel, Rgb, Rgba};
 
+/// Default luminance ramp used by [`crate::image_proc::ImageEngine`], ordered from darkest to
+/// brightest glyph.
+pub const DEFAULT: [char; 10] = [' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
+/// A fine, high-detail luminance ramp, ordered from darkest to brightest glyph.
+const FINE: &str = " .'`^\",:;Il!i><~+_-?][}{1)(|\\/tfjrxnuvczXYUJCLQ0OZmwqpdbkhao*#MW&8%B@$";
+
+/// An ordered, dark-to-bright set of glyphs used to map pixel luminance to a character.
+///
+/// * `shallow` ships the coarse 10-glyph ramp also used as [`DEFAULT`]
+/// * `deep` ships a ~70-glyph ramp for high-detail output
+/// * `custom` accepts any user-supplied dark-to-bright string
+///
+/// Select one via [`crate::image_proc::ImageEngine::with_ramp`].
+pub struct CharRamp {
+    glyphs: Vec<char>,
+}
+
+impl CharRamp {
+    /// The coarse 10-glyph ramp (` .:-=+*#%@`), also used as the engine's default.
+    pub fn shallow() -> Self {
+        Self {
+            glyphs: DEFAULT.to_vec(),
+        }
+    }
+
+    /// A fine ~70-glyph ramp for high-detail output.
+    pub fn deep() -> Self {
+        Self {
+            glyphs: FINE.chars().collect(),
+        }
+    }
+
+    /// Build a ramp from any ordered dark-to-bright string of glyphs.
+    ///
+    /// Falls back to [`CharRamp::shallow`] if `ramp` is empty, since an empty ramp has no glyph
+    /// to map luminance onto.
+    ///
+    /// * `ramp`: glyphs ordered from darkest to brightest
+    pub fn custom(ramp: &str) -> Self {
+        if ramp.is_empty() {
+            return Self::shallow();
+        }
+
+        Self {
+            glyphs: ramp.chars().collect(),
+        }
+    }
+
+    /// Pick the glyph for a luminance normalized to `0.0..=1.0`.
+    pub(crate) fn glyph_for(&self, normalized_luminance: f64) -> char {
+        self.glyphs[(normalized_luminance * (self.glyphs.len() - 1) as f64) as usize]
+    }
+}
+
+impl Default for CharRamp {
+    fn default() -> Self {
+        Self::shallow()
+    }
+}
+
 pub struct Ascii {
     color: Rgb<u8>,
     ch: AsciiChar,
@@ -1,20 +1,94 @@
-use std::{error::Error, io};
+use std::io::{Cursor, Read};
+use std::time::Duration;
+use std::{error::Error, io, thread};
 
-use ansi_term::Color;
+use ab_glyph::{Font, FontArc, PxScale, ScaleFont};
+use ansi_term::{Color, Style};
+use image::codecs::gif::GifDecoder;
 use image::imageops::FilterType;
 use image::DynamicImage;
 use image::Rgba;
+use image::{AnimationDecoder, RgbaImage};
 
-use crate::ascii::DEFAULT;
+use crate::ascii::CharRamp;
+
+/// ANSI escape sequence that moves the cursor to the top-left of the terminal, used by
+/// [`ImageEngine::render_animation`] to overdraw each frame in place.
+const ANSI_CURSOR_HOME: &str = "\x1b[H";
+
+/// ANSI escape sequence that clears the whole terminal, used once before the first frame of
+/// [`ImageEngine::render_animation`] so leftover scrollback doesn't show through.
+const ANSI_CLEAR_SCREEN: &str = "\x1b[2J";
+
+/// ANSI escape sequence that clears from the cursor to the end of the current line, used by
+/// [`ImageEngine::render_animation`] after each row so a shorter frame doesn't leave glyphs from
+/// a wider previous frame's row on screen, without the full-screen flicker of [`ANSI_CLEAR_SCREEN`].
+const ANSI_CLEAR_LINE: &str = "\x1b[K";
+
+/// ANSI escape sequence that resets all text attributes, used by [`ImageEngine::render_animation`]
+/// before clearing so a frame that ended mid-color can't bleed into the cleared screen.
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Bundled monospaced font used by [`ImageEngine::render_to_image`] to rasterize ASCII art.
+const MONOSPACE_FONT_BYTES: &[u8] = include_bytes!("../assets/DejaVuSansMono.ttf");
+
+/// Point size used to rasterize glyphs in [`ImageEngine::render_to_image`].
+const GLYPH_PX_SCALE: f32 = 16.0;
+
+/// How RGB pixel color is threaded into the rendered ASCII text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenderMode {
+    /// Colored glyphs on the terminal's default background (the original behavior).
+    #[default]
+    Foreground,
+    /// Colored blocks: the pixel color is set as the ANSI background and a space is printed,
+    /// so the output reads like a low-res image.
+    Background,
+    /// No ANSI color at all, glyphs only.
+    Monochrome,
+}
+
+/// Raw pixel layout of frames read by [`ImageEngine::stream_frames`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawPixelFormat {
+    /// A single 8-bit grayscale sample per pixel.
+    Gray8,
+    /// 8-bit red, green, blue, and alpha samples per pixel.
+    Rgba8,
+}
+
+impl RawPixelFormat {
+    fn bytes_per_pixel(self) -> usize {
+        match self {
+            RawPixelFormat::Gray8 => 1,
+            RawPixelFormat::Rgba8 => 4,
+        }
+    }
+}
 
 /// Engine for rendering rgba images to ascii text
 ///
 /// * `source`: DynamicImage
-/// * `edge_map`: TODO: implement Edge detection methods
+/// * `edge_map`: per-pixel `(magnitude, direction)` produced by [`ImageEngine::compute_edges`],
+///   sized to `edge_dimensions`. `None` until `compute_edges` has been called.
+/// * `frames`: decoded animation frames with their per-frame delay, populated by
+///   [`ImageEngine::from_gif_slice`]. `None` for engines constructed from a single image.
+/// * `ramp`: the active [`CharRamp`] used to pick a glyph by luminance
+/// * `mode`: how pixel color is applied, see [`RenderMode`]
+/// * `color_enabled`: whether any ANSI color escapes are emitted at all
+/// * `use_256_color`: quantize truecolor pixels to the nearest xterm-256 palette index
+/// * `image_background`: background color used behind glyphs by [`ImageEngine::render_to_image`]
 pub struct ImageEngine {
     source: DynamicImage,
-    #[allow(unused)]
     edge_map: Option<Vec<(u8, u8)>>,
+    edge_dimensions: (u32, u32),
+    edge_threshold: u8,
+    mode: RenderMode,
+    color_enabled: bool,
+    use_256_color: bool,
+    frames: Option<Vec<(RgbaImage, Duration)>>,
+    ramp: CharRamp,
+    image_background: Rgba<u8>,
 }
 
 impl ImageEngine {
@@ -37,7 +111,15 @@ impl ImageEngine {
     pub fn new(source: DynamicImage) -> Self {
         Self {
             source,
-            edge_map: None, // TODO: Implement edge detection
+            edge_map: None,
+            edge_dimensions: (0, 0),
+            edge_threshold: 0,
+            frames: None,
+            ramp: CharRamp::default(),
+            mode: RenderMode::default(),
+            color_enabled: true,
+            use_256_color: false,
+            image_background: Rgba([0, 0, 0, 255]),
         }
     }
 
@@ -61,9 +143,409 @@ impl ImageEngine {
         Ok(Self {
             source: image,
             edge_map: None,
+            edge_dimensions: (0, 0),
+            edge_threshold: 0,
+            frames: None,
+            ramp: CharRamp::default(),
+            mode: RenderMode::default(),
+            color_enabled: true,
+            use_256_color: false,
+            image_background: Rgba([0, 0, 0, 255]),
         })
     }
 
+    /// Construct a new engine from the bytes of an animated GIF, decoding every frame up front
+    /// along with its delay so [`ImageEngine::render_animation`] can play it back.
+    ///
+    /// The engine's single-frame `source` is set to the GIF's first frame, so
+    /// [`ImageEngine::render_to_text`] and [`ImageEngine::get_ascii_as_string`] still work as a
+    /// still preview; use `render_animation` to play all frames.
+    ///
+    /// # Usage
+    /// ```rust
+    ///     use rustascii::{image_proc::ImageEngine};
+    ///     use std::{error::Error, io::stdout};
+    ///
+    ///     fn main() -> Result<(), Box<dyn Error>> {
+    ///         let source = include_bytes!("your-animation.gif");
+    ///         let engine = ImageEngine::from_gif_slice(source)?;
+    ///
+    ///         let mut writer = stdout();
+    ///         engine.render_animation(&mut writer, 0, Some(128), None, None)?;
+    ///         Ok(())
+    ///     }
+    /// ```
+    ///
+    /// * `source`: the raw bytes of an animated GIF
+    pub fn from_gif_slice(source: &[u8]) -> Result<Self, Box<dyn Error>> {
+        let decoder = GifDecoder::new(Cursor::new(source))?;
+        let decoded_frames = decoder.into_frames().collect_frames()?;
+
+        let frames: Vec<(RgbaImage, Duration)> = decoded_frames
+            .into_iter()
+            .map(|frame| {
+                let (numerator, denominator) = frame.delay().numer_denom_ms();
+                let millis = numerator as u64 / denominator.max(1) as u64;
+                (frame.into_buffer(), Duration::from_millis(millis))
+            })
+            .collect();
+
+        let first_frame = frames
+            .first()
+            .map(|(image, _)| DynamicImage::ImageRgba8(image.clone()))
+            .ok_or("gif contains no frames")?;
+
+        Ok(Self {
+            source: first_frame,
+            edge_map: None,
+            edge_dimensions: (0, 0),
+            edge_threshold: 0,
+            frames: Some(frames),
+            ramp: CharRamp::default(),
+            mode: RenderMode::default(),
+            color_enabled: true,
+            use_256_color: false,
+            image_background: Rgba([0, 0, 0, 255]),
+        })
+    }
+
+    /// Construct an engine with no backing image, for configuring render options (ramp, mode,
+    /// color) ahead of [`ImageEngine::stream_frames`], where frames arrive from a reader instead
+    /// of a decoded image.
+    pub fn for_streaming() -> Self {
+        Self::new(DynamicImage::new_rgba8(1, 1))
+    }
+
+    /// Read fixed-size raw video frames from `reader` one after another, convert each to ASCII,
+    /// and write the result to `writer`, flushing per frame. Reading stops cleanly once `reader`
+    /// can no longer fill a full frame.
+    ///
+    /// Meant for piping from FFmpeg:
+    /// `ffmpeg ... -f rawvideo -pix_fmt rgba - | rustascii --raw ...`
+    ///
+    /// # Usage
+    /// ```rust
+    ///     use rustascii::image_proc::{ImageEngine, RawPixelFormat};
+    ///     use std::{error::Error, io::stdin};
+    ///
+    ///     fn main() -> Result<(), Box<dyn Error>> {
+    ///         let engine = ImageEngine::for_streaming();
+    ///         let mut output = Vec::new();
+    ///
+    ///         engine.stream_frames(
+    ///             stdin(),
+    ///             &mut output,
+    ///             RawPixelFormat::Rgba8,
+    ///             1920,
+    ///             1080,
+    ///             0,
+    ///             Some(128),
+    ///             None,
+    ///         )?;
+    ///         Ok(())
+    ///     }
+    /// ```
+    ///
+    /// * `reader`: source of raw frames, e.g. FFmpeg's stdout piped to stdin
+    /// * `writer`: destination for the rendered ASCII frames
+    /// * `format`: pixel layout of each incoming frame
+    /// * `frame_width`: width in pixels of each incoming frame
+    /// * `frame_height`: height in pixels of each incoming frame
+    /// * `alpha_threshold`: lowest possible alpha value for ascii text to be rendered
+    /// * `out_width`: width of the rendered ascii text
+    /// * `out_height`: height of the rendered ascii text
+    #[allow(clippy::too_many_arguments)]
+    pub fn stream_frames<R: Read, W: io::Write>(
+        &self,
+        mut reader: R,
+        mut writer: W,
+        format: RawPixelFormat,
+        frame_width: u32,
+        frame_height: u32,
+        alpha_threshold: u8,
+        out_width: Option<u32>,
+        out_height: Option<u32>,
+    ) -> io::Result<()> {
+        let (out_width, out_height) =
+            Self::dimensions_for(frame_width, frame_height, out_width, out_height);
+        let frame_size =
+            frame_width as usize * frame_height as usize * format.bytes_per_pixel();
+        let mut raw_frame = vec![0u8; frame_size];
+
+        loop {
+            if let Err(error) = reader.read_exact(&mut raw_frame) {
+                if error.kind() == io::ErrorKind::UnexpectedEof {
+                    break;
+                }
+                return Err(error);
+            }
+
+            let rgba = Self::raw_frame_to_rgba(&raw_frame, format, frame_width, frame_height);
+            let resized = DynamicImage::ImageRgba8(rgba)
+                .resize_exact(out_width, out_height, FilterType::Nearest)
+                .to_rgba8();
+
+            self.render_frame_to_text(
+                &resized,
+                &mut writer,
+                alpha_threshold,
+                out_width,
+                out_height,
+                false,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Convert a raw frame buffer of the given pixel layout into an `RgbaImage`.
+    fn raw_frame_to_rgba(raw: &[u8], format: RawPixelFormat, width: u32, height: u32) -> RgbaImage {
+        match format {
+            RawPixelFormat::Rgba8 => RgbaImage::from_raw(width, height, raw.to_vec())
+                .expect("raw frame buffer size must match width * height * 4"),
+            RawPixelFormat::Gray8 => {
+                let mut rgba = Vec::with_capacity(raw.len() * 4);
+                for &sample in raw {
+                    rgba.extend_from_slice(&[sample, sample, sample, 255]);
+                }
+                RgbaImage::from_raw(width, height, rgba)
+                    .expect("raw frame buffer size must match width * height")
+            }
+        }
+    }
+
+    /// Select the [`CharRamp`] used to map pixel luminance to a glyph.
+    ///
+    /// * `ramp`: the ramp to render with, e.g. [`CharRamp::deep`] or [`CharRamp::custom`]
+    pub fn with_ramp(mut self, ramp: CharRamp) -> Self {
+        self.ramp = ramp;
+        self
+    }
+
+    /// Select how pixel color is applied: foreground glyphs, background blocks, or plain
+    /// monochrome. See [`RenderMode`].
+    pub fn with_mode(mut self, mode: RenderMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Enable or disable ANSI color output entirely. When disabled, no color escapes are
+    /// emitted regardless of `mode`; callers should resolve this from `NO_COLOR` and a
+    /// `--color` flag before constructing the engine.
+    pub fn with_color_enabled(mut self, enabled: bool) -> Self {
+        self.color_enabled = enabled;
+        self
+    }
+
+    /// Quantize truecolor pixels to the nearest xterm-256 palette index, for terminals that
+    /// lack truecolor support.
+    pub fn with_256_color(mut self, enabled: bool) -> Self {
+        self.use_256_color = enabled;
+        self
+    }
+
+    /// Set the background color painted behind glyphs by [`ImageEngine::render_to_image`].
+    pub fn with_background(mut self, background: Rgba<u8>) -> Self {
+        self.image_background = background;
+        self
+    }
+
+    /// Rasterize the rendered ASCII art into an actual image using a bundled monospaced font,
+    /// rather than emitting ANSI terminal text.
+    ///
+    /// Each glyph is drawn into a cell sized to the font's advance and line height, in its
+    /// per-pixel RGB color, over `image_background` (see [`ImageEngine::with_background`]).
+    /// Transparent pixels (alpha at or below `alpha_threshold`) are left as background.
+    ///
+    /// # Usage
+    /// ```rust
+    ///     use rustascii::{image_proc::ImageEngine};
+    ///     use std::error::Error;
+    ///
+    ///     fn main() -> Result<(), Box<dyn Error>> {
+    ///         let source = include_bytes!("your-path");
+    ///         let engine = ImageEngine::from_slice(source)?;
+    ///
+    ///         let image = engine.render_to_image(0, Some(128), None);
+    ///         image.save("render.png")?;
+    ///         Ok(())
+    ///     }
+    /// ```
+    ///
+    /// * `alpha_threshold`: Lowest possible alpha value for ascii text to be rendered
+    /// * `width`: New width of the ascii text
+    /// * `height`: New height of the ascii text
+    pub fn render_to_image(
+        &self,
+        alpha_threshold: u8,
+        width: Option<u32>,
+        height: Option<u32>,
+    ) -> RgbaImage {
+        let (columns, rows) = self.calculate_dimensions(width, height);
+        let image = self
+            .source
+            .resize_exact(columns, rows, FilterType::Nearest)
+            .to_rgba8();
+
+        let font = FontArc::try_from_slice(MONOSPACE_FONT_BYTES)
+            .expect("bundled monospace font must be valid");
+        let scale = PxScale::from(GLYPH_PX_SCALE);
+        let scaled_font = font.as_scaled(scale);
+
+        let cell_width = scaled_font.h_advance(font.glyph_id(' ')).ceil() as u32;
+        let cell_height = scaled_font.height().ceil() as u32;
+
+        let mut canvas = RgbaImage::from_pixel(
+            columns * cell_width.max(1),
+            rows * cell_height.max(1),
+            self.image_background,
+        );
+
+        let maximum = image
+            .pixels()
+            .fold(0.0, |acc, pixel| self.get_grayscale_pixel(pixel).max(acc));
+
+        for (column, line, pixel) in image.enumerate_pixels() {
+            if pixel.0[3] <= alpha_threshold {
+                continue;
+            }
+
+            let glyph_char =
+                self.get_char_for_pixel(pixel, column, line, columns, rows, alpha_threshold, maximum);
+            let color = Rgba([pixel[0], pixel[1], pixel[2], 255]);
+
+            let cell_x = column * cell_width;
+            let cell_y = line * cell_height;
+            let glyph = font.glyph_id(glyph_char).with_scale_and_position(
+                scale,
+                ab_glyph::point(cell_x as f32, cell_y as f32 + scaled_font.ascent()),
+            );
+
+            if let Some(outline) = font.outline_glyph(glyph) {
+                let bounds = outline.px_bounds();
+                outline.draw(|glyph_x, glyph_y, coverage| {
+                    if coverage <= 0.0 {
+                        return;
+                    }
+
+                    let x = bounds.min.x as i64 + glyph_x as i64;
+                    let y = bounds.min.y as i64 + glyph_y as i64;
+                    if x < 0 || y < 0 || x >= canvas.width() as i64 || y >= canvas.height() as i64 {
+                        return;
+                    }
+                    let (x, y) = (x as u32, y as u32);
+
+                    let blended = Self::blend_pixel(*canvas.get_pixel(x, y), color, coverage);
+                    canvas.put_pixel(x, y, blended);
+                });
+            }
+        }
+
+        canvas
+    }
+
+    /// Alpha-blend `color` over `base` by `coverage` (`0.0..=1.0`), keeping `base`'s alpha.
+    fn blend_pixel(base: Rgba<u8>, color: Rgba<u8>, coverage: f32) -> Rgba<u8> {
+        let blend_channel = |base_channel: u8, color_channel: u8| -> u8 {
+            (base_channel as f32 * (1.0 - coverage) + color_channel as f32 * coverage).round() as u8
+        };
+
+        Rgba([
+            blend_channel(base[0], color[0]),
+            blend_channel(base[1], color[1]),
+            blend_channel(base[2], color[2]),
+            base[3],
+        ])
+    }
+
+    /// Compute a Sobel edge map over the resized grayscale render buffer, enabling
+    /// structure-aware glyphs in [`ImageEngine::get_char_for_pixel`].
+    ///
+    /// The source is resized to the same `(width, height)` the image will be rendered at and
+    /// convolved with the Sobel kernels
+    /// `Gx = [[-1,0,1],[-2,0,2],[-1,0,1]]` and `Gy` (its transpose), so the edge map lines up
+    /// pixel-for-pixel with the rendered grid instead of the source image's native resolution.
+    /// For every pixel this stores `(magnitude, direction)`, where `magnitude` is
+    /// `sqrt(gx² + gy²)` clamped into a `u8` and `direction` is the gradient angle
+    /// `atan2(gy, gx)` quantized into four bins (0°, 45°, 90°, 135°). Pixels on the buffer's
+    /// border, where the 3×3 window would overrun, are recorded with magnitude 0.
+    ///
+    /// Call this before rendering, with the same `width`/`height` you intend to render at; once
+    /// set, rendering methods emit a line-drawing glyph for any pixel whose magnitude exceeds
+    /// `edge_threshold`, falling back to the luminance ramp otherwise.
+    ///
+    /// # Usage
+    /// ```rust
+    ///     use rustascii::{image_proc::ImageEngine};
+    ///     use std::error::Error;
+    ///
+    ///     fn main() -> Result<(), Box<dyn Error>> {
+    ///         let source = include_bytes!("your-path");
+    ///         let mut engine = ImageEngine::from_slice(source)?;
+    ///         engine.compute_edges(64, Some(128), None);
+    ///         Ok(())
+    ///     }
+    /// ```
+    ///
+    /// * `edge_threshold`: minimum gradient magnitude for a pixel to be rendered as an edge glyph
+    /// * `width`: width of the rendered ascii text, as passed to the render call
+    /// * `height`: height of the rendered ascii text, as passed to the render call
+    pub fn compute_edges(&mut self, edge_threshold: u8, width: Option<u32>, height: Option<u32>) {
+        let (width, height) = self.calculate_dimensions(width, height);
+        let gray = self
+            .source
+            .resize_exact(width, height, FilterType::Nearest)
+            .to_luma8();
+        let (width, height) = gray.dimensions();
+
+        const SOBEL_X: [[i32; 3]; 3] = [[-1, 0, 1], [-2, 0, 2], [-1, 0, 1]];
+        const SOBEL_Y: [[i32; 3]; 3] = [[-1, -2, -1], [0, 0, 0], [1, 2, 1]];
+
+        let mut edges = Vec::with_capacity((width * height) as usize);
+
+        for y in 0..height {
+            for x in 0..width {
+                if x == 0 || y == 0 || x + 1 >= width || y + 1 >= height {
+                    edges.push((0, 0));
+                    continue;
+                }
+
+                let mut gx = 0i32;
+                let mut gy = 0i32;
+                for ky in 0..3u32 {
+                    for kx in 0..3u32 {
+                        let sample = gray.get_pixel(x + kx - 1, y + ky - 1).0[0] as i32;
+                        gx += SOBEL_X[ky as usize][kx as usize] * sample;
+                        gy += SOBEL_Y[ky as usize][kx as usize] * sample;
+                    }
+                }
+
+                let magnitude = (((gx * gx + gy * gy) as f64).sqrt()).clamp(0.0, 255.0) as u8;
+                let direction = Self::quantize_direction((gy as f64).atan2(gx as f64));
+
+                edges.push((magnitude, direction));
+            }
+        }
+
+        self.edge_dimensions = (width, height);
+        self.edge_threshold = edge_threshold;
+        self.edge_map = Some(edges);
+    }
+
+    /// Quantize a gradient angle in radians into one of four direction bins: `0` for ~0°
+    /// (horizontal, `-`), `1` for ~45° (`/`), `2` for ~90° (vertical, `|`), `3` for ~135° (`\`).
+    fn quantize_direction(angle_radians: f64) -> u8 {
+        let degrees = angle_radians.to_degrees();
+        let normalized = ((degrees % 180.0) + 180.0) % 180.0;
+
+        match normalized {
+            a if !(22.5..157.5).contains(&a) => 0,
+            a if (22.5..67.5).contains(&a) => 1,
+            a if (67.5..112.5).contains(&a) => 2,
+            _ => 3,
+        }
+    }
+
     /// Process the image, with scaling, and write the output to a writer.
     ///
     /// Note that either `width` or `height` must be Some(value)
@@ -122,35 +604,155 @@ impl ImageEngine {
             .resize_exact(width, height, FilterType::Nearest)
             .to_rgba8();
 
-        let mut prev_color: Option<Color> = None;
+        self.render_frame_to_text(&image, writer, alpha_threshold, width, height, false)
+    }
+
+    /// Play back the frames decoded by [`ImageEngine::from_gif_slice`], rendering each frame to
+    /// ASCII and overdrawing the previous one in place using ANSI cursor-home and clear
+    /// sequences, sleeping between frames for that frame's GIF delay.
+    ///
+    /// `loops` controls how many times the animation repeats: `None` plays through the frames
+    /// once, `Some(0)` loops forever, and `Some(n)` for `n > 0` loops `n` times.
+    ///
+    /// # Usage
+    /// ```rust
+    ///     use rustascii::{image_proc::ImageEngine};
+    ///     use std::{error::Error, io::stdout};
+    ///
+    ///     fn main() -> Result<(), Box<dyn Error>> {
+    ///         let source = include_bytes!("your-animation.gif");
+    ///         let engine = ImageEngine::from_gif_slice(source)?;
+    ///
+    ///         let mut writer = stdout();
+    ///         engine.render_animation(&mut writer, 0, Some(128), None, Some(0))?;
+    ///         Ok(())
+    ///     }
+    /// ```
+    ///
+    /// * `writer`: Some thing that implements `io::Write`
+    /// * `alpha_threshold`: Lowest possible alpha value for ascii text to be rendered
+    /// * `width`: New width of the ascii text
+    /// * `height`: New height of the ascii text
+    /// * `loops`: how many times to repeat the animation; `None` plays it once, `Some(0)` loops
+    ///   forever
+    pub fn render_animation(
+        &self,
+        writer: &mut dyn io::Write,
+        alpha_threshold: u8,
+        width: Option<u32>,
+        height: Option<u32>,
+        loops: Option<u32>,
+    ) -> io::Result<()> {
+        let frames = self.frames.as_ref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "engine has no animation frames; construct it with ImageEngine::from_gif_slice",
+            )
+        })?;
+
+        let (width, height) = self.calculate_dimensions(width, height);
+
+        let mut completed_loops = 0u32;
+        let mut is_first_frame = true;
+        loop {
+            for (frame, delay) in frames {
+                let image = DynamicImage::ImageRgba8(frame.clone())
+                    .resize_exact(width, height, FilterType::Nearest)
+                    .to_rgba8();
+
+                if is_first_frame {
+                    write!(writer, "{ANSI_CLEAR_SCREEN}{ANSI_CURSOR_HOME}")?;
+                    is_first_frame = false;
+                } else {
+                    write!(writer, "{ANSI_RESET}{ANSI_CURSOR_HOME}")?;
+                }
+                self.render_frame_to_text(&image, writer, alpha_threshold, width, height, true)?;
+                writer.flush()?;
+
+                thread::sleep(*delay);
+            }
+
+            completed_loops += 1;
+            match loops {
+                Some(0) => continue,
+                Some(limit) if completed_loops < limit => continue,
+                _ => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write a single already-resized RGBA frame to `writer` as colored ASCII text, reused by
+    /// [`ImageEngine::render_to_text`], [`ImageEngine::stream_frames`], and
+    /// [`ImageEngine::render_animation`].
+    ///
+    /// * `clear_to_eol`: when set, clears from the cursor to the end of each line before moving
+    ///   to the next one, so a shorter animation frame doesn't leave a previous frame's glyphs
+    ///   trailing on screen
+    fn render_frame_to_text(
+        &self,
+        image: &image::RgbaImage,
+        writer: &mut dyn io::Write,
+        alpha_threshold: u8,
+        width: u32,
+        height: u32,
+        clear_to_eol: bool,
+    ) -> io::Result<()> {
+        let mut prev_style: Option<Style> = None;
         let mut current_line = 0;
 
         let maximum = image
             .pixels()
             .fold(0.0, |acc, pixel| self.get_grayscale_pixel(pixel).max(acc));
 
-        for (_, line, pixel) in image.enumerate_pixels() {
+        for (column, line, pixel) in image.enumerate_pixels() {
             if current_line < line {
                 current_line = line;
-                if let Some(color) = prev_color {
-                    write!(writer, "{}", color.suffix())?;
-                    prev_color = None;
+                if let Some(style) = prev_style {
+                    write!(writer, "{}", style.suffix())?;
+                    prev_style = None;
                 };
+                if clear_to_eol {
+                    write!(writer, "{ANSI_CLEAR_LINE}")?;
+                }
                 writeln!(writer)?;
             }
 
-            let color = Color::RGB(pixel[0], pixel[1], pixel[2]);
-            if prev_color != Some(color) {
-                write!(writer, "{}", color.prefix())?;
+            let is_transparent = pixel.0[3] <= alpha_threshold;
+            let style = if is_transparent {
+                Style::default()
+            } else {
+                self.style_for_pixel(pixel)
+            };
+            if prev_style != Some(style) {
+                write!(writer, "{}", style.prefix())?;
             }
-            prev_color = Some(color);
+            prev_style = Some(style);
 
-            let char_for_pixel = self.get_char_for_pixel(pixel, alpha_threshold, maximum);
-            write!(writer, "{char_for_pixel}")?;
+            let char_for_pixel = self.get_char_for_pixel(
+                pixel,
+                column,
+                line,
+                width,
+                height,
+                alpha_threshold,
+                maximum,
+            );
+            let glyph = if self.mode == RenderMode::Background && !is_transparent && self.color_enabled
+            {
+                ' '
+            } else {
+                char_for_pixel
+            };
+            write!(writer, "{glyph}")?;
         }
 
-        if let Some(color) = prev_color {
-            write!(writer, "{}", color.prefix())?;
+        if let Some(style) = prev_style {
+            write!(writer, "{}", style.suffix())?;
+        }
+        if clear_to_eol {
+            write!(writer, "{ANSI_CLEAR_LINE}")?;
         }
 
         writer.flush()?;
@@ -177,47 +779,143 @@ impl ImageEngine {
             .to_rgba8();
 
         let mut output = String::new();
-        let mut prev_color: Option<Color> = None;
+        let mut prev_style: Option<Style> = None;
         let mut current_line = 0;
 
         let maximum = image
             .pixels()
             .fold(0.0, |acc, pixel| self.get_grayscale_pixel(pixel).max(acc));
 
-        for (_, line, pixel) in image.enumerate_pixels() {
+        for (column, line, pixel) in image.enumerate_pixels() {
             if current_line < line {
                 current_line = line;
-                if let Some(color) = prev_color {
-                    output.push_str(&format!("{}", color.suffix()));
-                    prev_color = None;
+                if let Some(style) = prev_style {
+                    output.push_str(&format!("{}", style.suffix()));
+                    prev_style = None;
                 };
                 output.push('\n');
             }
 
-            let color = Color::RGB(pixel[0], pixel[1], pixel[2]);
-            if prev_color != Some(color) {
-                output.push_str(&format!("{}", color.prefix()));
+            let is_transparent = pixel.0[3] <= alpha_threshold;
+            let style = if is_transparent {
+                Style::default()
+            } else {
+                self.style_for_pixel(pixel)
+            };
+            if prev_style != Some(style) {
+                output.push_str(&format!("{}", style.prefix()));
             }
-            prev_color = Some(color);
+            prev_style = Some(style);
 
-            let char_for_pixel = self.get_char_for_pixel(pixel, alpha_threshold, maximum);
-            output.push_str(&format!("{char_for_pixel}"));
+            let char_for_pixel = self.get_char_for_pixel(
+                pixel,
+                column,
+                line,
+                width,
+                height,
+                alpha_threshold,
+                maximum,
+            );
+            let glyph = if self.mode == RenderMode::Background && !is_transparent && self.color_enabled
+            {
+                ' '
+            } else {
+                char_for_pixel
+            };
+            output.push_str(&format!("{glyph}"));
         }
 
-        if let Some(color) = prev_color {
-            output.push_str(&format!("{}", color.prefix()));
+        if let Some(style) = prev_style {
+            output.push_str(&format!("{}", style.suffix()));
         }
 
         output
     }
 
-    fn get_char_for_pixel(&self, pixel: &Rgba<u8>, alpha_threshold: u8, maximum: f64) -> char {
-        let gray_scale = self.get_grayscale_pixel(pixel) / maximum;
+    #[allow(clippy::too_many_arguments)]
+    fn get_char_for_pixel(
+        &self,
+        pixel: &Rgba<u8>,
+        column: u32,
+        line: u32,
+        width: u32,
+        height: u32,
+        alpha_threshold: u8,
+        maximum: f64,
+    ) -> char {
         if pixel.0[3] <= alpha_threshold {
             return ' ';
         }
 
-        DEFAULT[(gray_scale * (DEFAULT.len() - 1) as f64) as usize]
+        if let Some(edge_glyph) = self.get_edge_glyph(column, line, width, height) {
+            return edge_glyph;
+        }
+
+        let gray_scale = self.get_grayscale_pixel(pixel) / maximum;
+        self.ramp.glyph_for(gray_scale)
+    }
+
+    /// Look up the edge glyph for a rendered-grid pixel at `(column, line)`, mapping it back
+    /// onto the edge map's native resolution. Returns `None` when there is no edge map yet, or
+    /// when the pixel's magnitude does not exceed `edge_threshold`.
+    fn get_edge_glyph(&self, column: u32, line: u32, width: u32, height: u32) -> Option<char> {
+        let edge_map = self.edge_map.as_ref()?;
+        let (native_width, native_height) = self.edge_dimensions;
+
+        let native_x = (column as u64 * native_width as u64 / width as u64) as u32;
+        let native_y = (line as u64 * native_height as u64 / height as u64) as u32;
+        let index = (native_y * native_width + native_x) as usize;
+
+        let &(magnitude, direction) = edge_map.get(index)?;
+        if magnitude <= self.edge_threshold {
+            return None;
+        }
+
+        Some(match direction {
+            0 => '-',
+            1 => '/',
+            2 => '|',
+            _ => '\\',
+        })
+    }
+
+    /// Build the ANSI style for a visible pixel, honoring `mode` and `use_256_color`.
+    ///
+    /// Returns [`Style::default`] unconditionally when `color_enabled` is `false`, so no ANSI
+    /// color escapes are emitted.
+    fn style_for_pixel(&self, pixel: &Rgba<u8>) -> Style {
+        if !self.color_enabled {
+            return Style::default();
+        }
+
+        let color = if self.use_256_color {
+            Color::Fixed(Self::nearest_xterm256(pixel[0], pixel[1], pixel[2]))
+        } else {
+            Color::RGB(pixel[0], pixel[1], pixel[2])
+        };
+
+        match self.mode {
+            RenderMode::Foreground => Style::new().fg(color),
+            RenderMode::Background => Style::new().on(color),
+            RenderMode::Monochrome => Style::default(),
+        }
+    }
+
+    /// Map an RGB color to the closest index in the xterm-256 6×6×6 color cube (indices 16-231).
+    fn nearest_xterm256(r: u8, g: u8, b: u8) -> u8 {
+        const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+        let nearest_level = |channel: u8| -> u8 {
+            LEVELS
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, &level)| (level as i32 - channel as i32).abs())
+                .map(|(index, _)| index as u8)
+                .unwrap_or(0)
+        };
+
+        let (ri, gi, bi) = (nearest_level(r), nearest_level(g), nearest_level(b));
+        16 + 36 * ri + 6 * gi + bi
     }
 
     fn get_grayscale_pixel(&self, pixel: &Rgba<u8>) -> f64 {
@@ -227,18 +925,30 @@ impl ImageEngine {
     }
 
     fn calculate_dimensions(&self, width: Option<u32>, height: Option<u32>) -> (u32, u32) {
+        Self::dimensions_for(self.source.width(), self.source.height(), width, height)
+    }
+
+    /// Resolve the missing half of a width/height pair against a reference aspect ratio,
+    /// halving the horizontal axis to compensate for terminal glyphs being roughly twice as
+    /// tall as they are wide.
+    fn dimensions_for(
+        ref_width: u32,
+        ref_height: u32,
+        width: Option<u32>,
+        height: Option<u32>,
+    ) -> (u32, u32) {
         (
             width.unwrap_or_else(|| {
                 (height.expect("Either width or weight must be specified") as f64
-                    * self.source.width() as f64
-                    / self.source.height() as f64
+                    * ref_width as f64
+                    / ref_height as f64
                     / 2.0)
                     .ceil() as u32
             }),
             height.unwrap_or_else(|| {
                 (width.expect("Either height or width must be specified") as f64
-                    * self.source.height() as f64
-                    / self.source.width() as f64
+                    * ref_height as f64
+                    / ref_width as f64
                     / 2.0)
                     .ceil() as u32
             }),
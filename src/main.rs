@@ -1,6 +1,6 @@
 use std::error::Error;
 use std::fs;
-use std::io::stdout;
+use std::io::{stdin, stdout};
 use std::path::PathBuf;
 use std::str::FromStr;
 
@@ -18,7 +18,64 @@ struct Arguments {
     #[arg(short, long)]
     threshold: Option<u8>,
 
-    path: String,
+    /// Render structure-aware line-drawing glyphs for pixels whose Sobel gradient magnitude
+    /// exceeds this value, falling back to the luminance ramp elsewhere. Unset disables edge
+    /// detection entirely.
+    #[arg(long)]
+    edge_threshold: Option<u8>,
+
+    /// Play an animated GIF in place instead of rendering a single frame
+    #[arg(long)]
+    animate: bool,
+
+    /// Repeat the animation instead of playing it once; requires `--animate`
+    #[arg(long)]
+    r#loop: bool,
+
+    /// Number of times to repeat the animation when `--loop` is set; 0 loops forever
+    #[arg(long, default_value_t = 0)]
+    loop_count: u32,
+
+    /// Character ramp to render with: `shallow`, `deep`, or a literal dark-to-bright string
+    #[arg(long)]
+    charset: Option<String>,
+
+    /// How pixel color is applied: `foreground` (default), `background`, or `monochrome`
+    #[arg(long, default_value = "foreground")]
+    mode: String,
+
+    /// Whether to emit ANSI color: `always`, `never`, or `auto` (honors `NO_COLOR`)
+    #[arg(long, default_value = "auto")]
+    color: String,
+
+    /// Quantize colors to the nearest xterm-256 palette index, for terminals without truecolor
+    #[arg(long = "256color")]
+    use_256_color: bool,
+
+    /// Read fixed-size raw video frames from stdin instead of decoding an image file, e.g.
+    /// `ffmpeg ... -f rawvideo -pix_fmt rgba - | rustascii --raw --frame-width 1920 --frame-height 1080`
+    #[arg(long)]
+    raw: bool,
+
+    /// Width in pixels of each incoming raw frame, required with `--raw`
+    #[arg(long)]
+    frame_width: Option<u32>,
+
+    /// Height in pixels of each incoming raw frame, required with `--raw`
+    #[arg(long)]
+    frame_height: Option<u32>,
+
+    /// Pixel layout of each incoming raw frame: `gray8` or `rgba8`
+    #[arg(long, default_value = "rgba8")]
+    pixel_format: String,
+
+    /// Render to a PNG image using a bundled monospaced font instead of printing ANSI text.
+    /// Not supported together with `--animate` or `--raw`.
+    #[arg(long)]
+    output: Option<PathBuf>,
+
+    /// Path to the image (or GIF) to render; omitted when using `--raw`
+    path: Option<String>,
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -31,15 +88,92 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
     let threshold = arguments.threshold.unwrap_or_default();
 
-    let path = PathBuf::from_str(&arguments.path)?;
+    let ramp = match arguments.charset.as_deref() {
+        Some("shallow") => ascii_rs::ascii::CharRamp::shallow(),
+        Some("deep") => ascii_rs::ascii::CharRamp::deep(),
+        Some(custom) => ascii_rs::ascii::CharRamp::custom(custom),
+        None => ascii_rs::ascii::CharRamp::default(),
+    };
 
-    let data = fs::read(path)?;
+    let mode = match arguments.mode.as_str() {
+        "background" => ascii_rs::image_proc::RenderMode::Background,
+        "monochrome" => ascii_rs::image_proc::RenderMode::Monochrome,
+        _ => ascii_rs::image_proc::RenderMode::Foreground,
+    };
+
+    let color_enabled = match arguments.color.as_str() {
+        "always" => true,
+        "never" => false,
+        _ => std::env::var_os("NO_COLOR").is_none(),
+    };
 
-    let image_engine = ascii_rs::image_proc::ImageEngine::from_slice(&data)?;
+    if arguments.output.is_some() && (arguments.animate || arguments.raw) {
+        return Err("--output cannot be combined with --animate or --raw".into());
+    }
 
     let mut writer = stdout();
 
-    image_engine.render_to_text(&mut writer, threshold, width, height)?;
+    if arguments.raw {
+        let frame_width = arguments
+            .frame_width
+            .ok_or("--frame-width is required with --raw")?;
+        let frame_height = arguments
+            .frame_height
+            .ok_or("--frame-height is required with --raw")?;
+        let pixel_format = match arguments.pixel_format.as_str() {
+            "gray8" => ascii_rs::image_proc::RawPixelFormat::Gray8,
+            "rgba8" => ascii_rs::image_proc::RawPixelFormat::Rgba8,
+            other => return Err(format!("unknown pixel format `{other}`").into()),
+        };
+
+        let image_engine = ascii_rs::image_proc::ImageEngine::for_streaming()
+            .with_ramp(ramp)
+            .with_mode(mode)
+            .with_color_enabled(color_enabled)
+            .with_256_color(arguments.use_256_color);
+        image_engine.stream_frames(
+            stdin(),
+            &mut writer,
+            pixel_format,
+            frame_width,
+            frame_height,
+            threshold,
+            width,
+            height,
+        )?;
+        return Ok(());
+    }
+
+    let path = arguments.path.ok_or("a path is required unless --raw is set")?;
+    let path = PathBuf::from_str(&path)?;
+    let data = fs::read(path)?;
+
+    if arguments.animate {
+        let image_engine = ascii_rs::image_proc::ImageEngine::from_gif_slice(&data)?
+            .with_ramp(ramp)
+            .with_mode(mode)
+            .with_color_enabled(color_enabled)
+            .with_256_color(arguments.use_256_color);
+        let loops = arguments.r#loop.then_some(arguments.loop_count);
+        image_engine.render_animation(&mut writer, threshold, width, height, loops)?;
+    } else {
+        let mut image_engine = ascii_rs::image_proc::ImageEngine::from_slice(&data)?
+            .with_ramp(ramp)
+            .with_mode(mode)
+            .with_color_enabled(color_enabled)
+            .with_256_color(arguments.use_256_color);
+
+        if let Some(edge_threshold) = arguments.edge_threshold {
+            image_engine.compute_edges(edge_threshold, width, height);
+        }
+
+        if let Some(output) = arguments.output {
+            let image = image_engine.render_to_image(threshold, width, height);
+            image.save(output)?;
+        } else {
+            image_engine.render_to_text(&mut writer, threshold, width, height)?;
+        }
+    }
 
     Ok(())
 }